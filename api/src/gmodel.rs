@@ -39,16 +39,35 @@ impl<'a> fmt::Display for Variable<'a> {
 /// It is the union of
 /// * [IRIs](https://www.w3.org/TR/rdf11-concepts/#dfn-iri),
 /// * [blank nodes](https://www.w3.org/TR/rdf11-concepts/#dfn-blank-node)
-/// * [literals](https://www.w3.org/TR/rdf11-concepts/#dfn-literal) and
-/// * [variable](https://www.w3.org/TR/2013/REC-sparql11-query-20130321/#QSynVariables).
+/// * [literals](https://www.w3.org/TR/rdf11-concepts/#dfn-literal),
+/// * [variable](https://www.w3.org/TR/2013/REC-sparql11-query-20130321/#QSynVariables) and
+/// * [RDF-star](https://w3c.github.io/rdf-star/cg-spec/editors_draft.html) quoted triples.
 ///
 /// The default string formatter is returning a N-Triples, Turtle and SPARQL compatible representation.
-#[derive(Eq, PartialEq, Debug, Clone, Copy, Hash)]
+///
+/// ```
+/// use rio_api::gmodel::{GeneralizedTerm, GeneralizedTriple, Variable};
+///
+/// let quoted = GeneralizedTerm::QuotedTriple(Box::new(GeneralizedTriple {
+///     subject: Variable { name: "s" }.into(),
+///     predicate: Variable { name: "p" }.into(),
+///     object: Variable { name: "o" }.into(),
+/// }));
+/// assert_eq!("<<?s ?p ?o>>", quoted.to_string());
+/// ```
+#[derive(Eq, PartialEq, Debug, Clone, Hash)]
 pub enum GeneralizedTerm<'a> {
     NamedNode(NamedNode<'a>),
     BlankNode(BlankNode<'a>),
     Literal(Literal<'a>),
     Variable(Variable<'a>),
+    /// An [RDF-star](https://w3c.github.io/rdf-star/cg-spec/editors_draft.html) quoted triple,
+    /// allowed to appear in subject or object position.
+    ///
+    /// Owned rather than borrowed, unlike the rest of `gmodel`: a quoted triple is built from
+    /// strict RDF's own `Term::Triple` (which owns its `Triple`), and there is no caller-owned
+    /// storage to borrow it from instead.
+    QuotedTriple(Box<GeneralizedTriple<'a>>),
 }
 
 impl<'a> From<NamedNode<'a>> for GeneralizedTerm<'a> {
@@ -90,42 +109,77 @@ impl<'a> From<Term<'a>> for GeneralizedTerm<'a> {
             Term::NamedNode(inner) => GeneralizedTerm::NamedNode(inner),
             Term::BlankNode(inner) => GeneralizedTerm::BlankNode(inner),
             Term::Literal(inner) => GeneralizedTerm::Literal(inner),
+            #[cfg(feature = "rdf-star")]
+            Term::Triple(inner) => GeneralizedTerm::QuotedTriple(Box::new((*inner).into())),
         }
     }
 }
 
 impl<'a> convert::TryFrom<GeneralizedTerm<'a>> for NamedNode<'a> {
-    type Error = StrictRdfError;
-    fn try_from(other: GeneralizedTerm<'a>) -> Result<NamedNode<'a>, StrictRdfError> {
+    type Error = StrictRdfError<'a>;
+    fn try_from(other: GeneralizedTerm<'a>) -> Result<NamedNode<'a>, StrictRdfError<'a>> {
         match other {
             GeneralizedTerm::NamedNode(inner) => Ok(inner),
-            GeneralizedTerm::BlankNode(_) => Err("Blankd node can not be used as predicate".into()),
-            GeneralizedTerm::Literal(_) => Err("Literal can not be used as predicate".into()),
-            GeneralizedTerm::Variable(_) => Err("Variable can not be converted to Term".into()),
+            GeneralizedTerm::BlankNode(inner) => Err(StrictRdfError::new(
+                GeneralizedTerm::BlankNode(inner),
+                "Blankd node can not be used as predicate",
+            )),
+            GeneralizedTerm::Literal(inner) => Err(StrictRdfError::new(
+                GeneralizedTerm::Literal(inner),
+                "Literal can not be used as predicate",
+            )),
+            GeneralizedTerm::Variable(inner) => Err(StrictRdfError::new(
+                GeneralizedTerm::Variable(inner),
+                "Variable can not be converted to Term",
+            )),
+            GeneralizedTerm::QuotedTriple(inner) => Err(StrictRdfError::new(
+                GeneralizedTerm::QuotedTriple(inner),
+                "Quoted triple can not be used as predicate",
+            )),
         }
     }
 }
 
 impl<'a> convert::TryFrom<GeneralizedTerm<'a>> for NamedOrBlankNode<'a> {
-    type Error = StrictRdfError;
-    fn try_from(other: GeneralizedTerm<'a>) -> Result<NamedOrBlankNode<'a>, StrictRdfError> {
+    type Error = StrictRdfError<'a>;
+    fn try_from(other: GeneralizedTerm<'a>) -> Result<NamedOrBlankNode<'a>, StrictRdfError<'a>> {
         match other {
             GeneralizedTerm::NamedNode(inner) => Ok(NamedOrBlankNode::NamedNode(inner)),
             GeneralizedTerm::BlankNode(inner) => Ok(NamedOrBlankNode::BlankNode(inner)),
-            GeneralizedTerm::Literal(_) => Err("Literal can not be used a subject".into()),
-            GeneralizedTerm::Variable(_) => Err("Variable can not be converted to Term".into()),
+            GeneralizedTerm::Literal(inner) => Err(StrictRdfError::new(
+                GeneralizedTerm::Literal(inner),
+                "Literal can not be used a subject",
+            )),
+            GeneralizedTerm::Variable(inner) => Err(StrictRdfError::new(
+                GeneralizedTerm::Variable(inner),
+                "Variable can not be converted to Term",
+            )),
+            GeneralizedTerm::QuotedTriple(inner) => Err(StrictRdfError::new(
+                GeneralizedTerm::QuotedTriple(inner),
+                "Quoted triple can not be used as subject",
+            )),
         }
     }
 }
 
 impl<'a> convert::TryFrom<GeneralizedTerm<'a>> for Term<'a> {
-    type Error = StrictRdfError;
-    fn try_from(other: GeneralizedTerm<'a>) -> Result<Term<'a>, StrictRdfError> {
+    type Error = StrictRdfError<'a>;
+    fn try_from(other: GeneralizedTerm<'a>) -> Result<Term<'a>, StrictRdfError<'a>> {
         match other {
             GeneralizedTerm::NamedNode(inner) => Ok(Term::NamedNode(inner)),
             GeneralizedTerm::BlankNode(inner) => Ok(Term::BlankNode(inner)),
             GeneralizedTerm::Literal(inner) => Ok(Term::Literal(inner)),
-            GeneralizedTerm::Variable(_) => Err("Variable can not be converted to Term".into()),
+            GeneralizedTerm::Variable(inner) => Err(StrictRdfError::new(
+                GeneralizedTerm::Variable(inner),
+                "Variable can not be converted to Term",
+            )),
+            #[cfg(feature = "rdf-star")]
+            GeneralizedTerm::QuotedTriple(triple) => Ok(Term::Triple(Box::new((*triple).try_into()?))),
+            #[cfg(not(feature = "rdf-star"))]
+            GeneralizedTerm::QuotedTriple(inner) => Err(StrictRdfError::new(
+                GeneralizedTerm::QuotedTriple(inner),
+                "Quoted triple can not be converted to Term without the `rdf-star` feature",
+            )),
         }
     }
 }
@@ -137,12 +191,82 @@ impl<'a> fmt::Display for GeneralizedTerm<'a> {
             GeneralizedTerm::BlankNode(node) => node.fmt(f),
             GeneralizedTerm::Literal(literal) => literal.fmt(f),
             GeneralizedTerm::Variable(variable) => variable.fmt(f),
+            GeneralizedTerm::QuotedTriple(triple) => write!(f, "<<{}>>", triple),
         }
     }
 }
 
 //
 
+/// A generalized [RDF triple](https://www.w3.org/TR/rdf11-concepts/#dfn-rdf-triple), without a
+/// graph name, used to hold the nested triple of a [`GeneralizedTerm::QuotedTriple`].
+///
+/// Subject and object may themselves be (or contain) a quoted triple, and `Display` recurses
+/// through nested quotations in any position. The strict-conversion `TryFrom` recurses only
+/// through a quoted triple in object position; a quoted triple in subject position is rejected.
+///
+/// ```
+/// use rio_api::gmodel::{GeneralizedTerm, GeneralizedTriple, Variable};
+///
+/// let inner = GeneralizedTriple {
+///     subject: Variable { name: "s" }.into(),
+///     predicate: Variable { name: "p" }.into(),
+///     object: Variable { name: "o" }.into(),
+/// };
+/// assert_eq!("?s ?p ?o", inner.to_string());
+///
+/// let nested = GeneralizedTriple {
+///     subject: GeneralizedTerm::QuotedTriple(Box::new(inner)),
+///     predicate: Variable { name: "p2" }.into(),
+///     object: Variable { name: "o2" }.into(),
+/// };
+/// assert_eq!("<<?s ?p ?o>> ?p2 ?o2", nested.to_string());
+/// ```
+#[derive(Eq, PartialEq, Debug, Clone, Hash)]
+pub struct GeneralizedTriple<'a> {
+    pub subject: GeneralizedTerm<'a>,
+    pub predicate: GeneralizedTerm<'a>,
+    pub object: GeneralizedTerm<'a>,
+}
+
+impl<'a> From<Triple<'a>> for GeneralizedTriple<'a> {
+    fn from(other: Triple<'a>) -> GeneralizedTriple<'a> {
+        GeneralizedTriple {
+            subject: other.subject.into(),
+            predicate: other.predicate.into(),
+            object: other.object.into(),
+        }
+    }
+}
+
+impl<'a> convert::TryFrom<GeneralizedTriple<'a>> for Triple<'a> {
+    type Error = StrictRdfError<'a>;
+    fn try_from(other: GeneralizedTriple<'a>) -> Result<Triple<'a>, StrictRdfError<'a>> {
+        Ok(Triple {
+            subject: other
+                .subject
+                .try_into()
+                .map_err(|e: StrictRdfError<'a>| e.in_position(TermPosition::Subject))?,
+            predicate: other
+                .predicate
+                .try_into()
+                .map_err(|e: StrictRdfError<'a>| e.in_position(TermPosition::Predicate))?,
+            object: other
+                .object
+                .try_into()
+                .map_err(|e: StrictRdfError<'a>| e.in_position(TermPosition::Object))?,
+        })
+    }
+}
+
+impl<'a> fmt::Display for GeneralizedTriple<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.subject, self.predicate, self.object)
+    }
+}
+
+//
+
 /// A generalized [RDF triple](https://www.w3.org/TR/rdf11-concepts/#dfn-rdf-triple) in a [RDF dataset](https://www.w3.org/TR/rdf11-concepts/#dfn-rdf-dataset).
 ///
 /// The default string formatter is returning a SPARQL representation.
@@ -190,39 +314,87 @@ impl<'a> From<Quad<'a>> for GeneralizedQuad<'a> {
 }
 
 impl<'a> convert::TryFrom<GeneralizedQuad<'a>> for Quad<'a> {
-    type Error = StrictRdfError;
+    type Error = StrictRdfError<'a>;
 
-    fn try_from(other: GeneralizedQuad<'a>) -> Result<Quad<'a>, StrictRdfError> {
+    fn try_from(other: GeneralizedQuad<'a>) -> Result<Quad<'a>, StrictRdfError<'a>> {
         Ok(Quad {
-            subject: other.subject.try_into()?,
-            predicate: other.predicate.try_into()?,
-            object: other.object.try_into()?,
+            subject: other
+                .subject
+                .try_into()
+                .map_err(|e: StrictRdfError<'a>| e.in_position(TermPosition::Subject))?,
+            predicate: other
+                .predicate
+                .try_into()
+                .map_err(|e: StrictRdfError<'a>| e.in_position(TermPosition::Predicate))?,
+            object: other
+                .object
+                .try_into()
+                .map_err(|e: StrictRdfError<'a>| e.in_position(TermPosition::Object))?,
             graph_name: other
                 .graph_name
-                .map(GeneralizedTerm::try_into)
+                .map(|graph_name| {
+                    GeneralizedTerm::try_into(graph_name)
+                        .map_err(|e: StrictRdfError<'a>| e.in_position(TermPosition::Graph))
+                })
                 .transpose()?,
         })
     }
 }
 
 impl<'a> convert::TryFrom<GeneralizedQuad<'a>> for Triple<'a> {
-    type Error = StrictRdfError;
+    type Error = StrictRdfError<'a>;
 
-    fn try_from(other: GeneralizedQuad<'a>) -> Result<Triple<'a>, StrictRdfError> {
+    fn try_from(other: GeneralizedQuad<'a>) -> Result<Triple<'a>, StrictRdfError<'a>> {
         match other.graph_name {
-            Some(_) => Err("Quad in named graph can not be converted to Triple".into()),
+            Some(graph_name) => Err(StrictRdfError::new(
+                graph_name,
+                "Quad in named graph can not be converted to Triple",
+            )
+            .in_position(TermPosition::Graph)),
             None => Ok(Triple {
-                subject: other.subject.try_into()?,
-                predicate: other.predicate.try_into()?,
-                object: other.object.try_into()?,
+                subject: other
+                    .subject
+                    .try_into()
+                    .map_err(|e: StrictRdfError<'a>| e.in_position(TermPosition::Subject))?,
+                predicate: other
+                    .predicate
+                    .try_into()
+                    .map_err(|e: StrictRdfError<'a>| e.in_position(TermPosition::Predicate))?,
+                object: other
+                    .object
+                    .try_into()
+                    .map_err(|e: StrictRdfError<'a>| e.in_position(TermPosition::Object))?,
             }),
         }
     }
 }
 
-impl<'a> fmt::Display for GeneralizedQuad<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if let Some(graph_name) = self.graph_name {
+/// Selects which RDF serialization [`GeneralizedQuad::serialize`] should emit.
+#[derive(Eq, PartialEq, Debug, Clone, Copy, Hash)]
+pub enum GeneralizedSyntax {
+    /// The SPARQL `GRAPH ?g { s p o .}` form, this crate's historical `Display` output.
+    Sparql,
+    /// The N-Quads `s p o g .` form. N-Quads has no syntax for a [`Variable`], so serializing a
+    /// quad that contains one in this syntax fails.
+    NQuads,
+    /// The Turtle-star `s p o .` form. Turtle has no notion of a named graph, so `graph_name` is
+    /// dropped.
+    Turtle,
+}
+
+impl<'a> GeneralizedQuad<'a> {
+    /// Writes this quad using the given [`GeneralizedSyntax`].
+    pub fn serialize(&self, syntax: GeneralizedSyntax, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match syntax {
+            GeneralizedSyntax::Sparql => self.fmt_sparql(f),
+            GeneralizedSyntax::NQuads => self.fmt_nquads(f),
+            GeneralizedSyntax::Turtle => self.fmt_turtle(f),
+        }
+    }
+
+    /// Writes this quad in the SPARQL `GRAPH ?g { s p o .}` form.
+    pub fn fmt_sparql(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(graph_name) = &self.graph_name {
             write!(f, "GRAPH {} {{ ", graph_name)?;
         }
         write!(f, "{} {} {} .", self.subject, self.predicate, self.object)?;
@@ -231,28 +403,455 @@ impl<'a> fmt::Display for GeneralizedQuad<'a> {
         }
         Ok(())
     }
+
+    /// Writes this quad in the N-Quads `s p o g .` form.
+    ///
+    /// Fails with [`fmt::Error`] if a [`Variable`] appears anywhere in the quad, since N-Quads
+    /// has no syntax for one. A [`Variable`] nested inside a [`GeneralizedTerm::QuotedTriple`]
+    /// counts too: `<<?s p o>> p2 o2 .` is not valid N-Quads either.
+    ///
+    /// ```
+    /// use rio_api::gmodel::{GeneralizedQuad, GeneralizedSyntax, GeneralizedTerm, GeneralizedTriple, Variable};
+    /// use rio_api::model::NamedNode;
+    /// use std::fmt;
+    ///
+    /// struct NQuads<'a>(&'a GeneralizedQuad<'a>);
+    /// impl<'a> fmt::Display for NQuads<'a> {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         self.0.serialize(GeneralizedSyntax::NQuads, f)
+    ///     }
+    /// }
+    ///
+    /// let inner = GeneralizedTriple {
+    ///     subject: Variable { name: "s" }.into(),
+    ///     predicate: Variable { name: "p" }.into(),
+    ///     object: Variable { name: "o" }.into(),
+    /// };
+    /// let quad = GeneralizedQuad {
+    ///     subject: GeneralizedTerm::QuotedTriple(Box::new(inner)),
+    ///     predicate: NamedNode { iri: "http://example.com/p2" }.into(),
+    ///     object: NamedNode { iri: "http://example.com/o2" }.into(),
+    ///     graph_name: None,
+    /// };
+    /// let mut out = String::new();
+    /// use fmt::Write;
+    /// assert!(write!(out, "{}", NQuads(&quad)).is_err());
+    /// ```
+    pub fn fmt_nquads(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn term_has_variable(term: &GeneralizedTerm) -> bool {
+            match term {
+                GeneralizedTerm::Variable(_) => true,
+                GeneralizedTerm::QuotedTriple(triple) => {
+                    term_has_variable(&triple.subject)
+                        || term_has_variable(&triple.predicate)
+                        || term_has_variable(&triple.object)
+                }
+                _ => false,
+            }
+        }
+        let has_variable = term_has_variable(&self.subject)
+            || term_has_variable(&self.predicate)
+            || term_has_variable(&self.object)
+            || matches!(&self.graph_name, Some(term) if term_has_variable(term));
+        if has_variable {
+            return Err(fmt::Error);
+        }
+        write!(f, "{} {} {}", self.subject, self.predicate, self.object)?;
+        if let Some(graph_name) = &self.graph_name {
+            write!(f, " {}", graph_name)?;
+        }
+        write!(f, " .")
+    }
+
+    /// Writes this quad in the Turtle-star `s p o .` form, dropping the graph name.
+    pub fn fmt_turtle(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {} .", self.subject, self.predicate, self.object)
+    }
+}
+
+impl<'a> fmt::Display for GeneralizedQuad<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.serialize(GeneralizedSyntax::Sparql, f)
+    }
 }
 
 //
 
+/// Which slot of a [`GeneralizedQuad`] (or [`GeneralizedTriple`]) a [`StrictRdfError`] was raised for.
+#[derive(Eq, PartialEq, Debug, Clone, Copy, Hash)]
+pub enum TermPosition {
+    Subject,
+    Predicate,
+    Object,
+    Graph,
+}
+
 /// An error raised when generalized RDF can not be converted to strict RDF.
+///
+/// Keeps the offending [`GeneralizedTerm`] around, so a caller that gets a `StrictRdfError` back
+/// from a failed `TryFrom` does not have to have cloned the term ahead of time just in case:
+/// [`StrictRdfError::into_term`] gives it back.
+///
+/// ```
+/// use rio_api::gmodel::{GeneralizedTerm, Variable};
+/// use rio_api::model::NamedNode;
+/// use std::convert::TryInto;
+///
+/// let term = GeneralizedTerm::Variable(Variable { name: "s" });
+/// let result: Result<NamedNode, _> = term.clone().try_into();
+/// let err = result.unwrap_err();
+/// assert_eq!(term, err.into_term());
+/// ```
 #[derive(Debug, Clone)]
-pub struct StrictRdfError {
+pub struct StrictRdfError<'a> {
     message: Box<str>,
+    term: GeneralizedTerm<'a>,
+    position: Option<TermPosition>,
 }
 
-impl<'a> fmt::Display for StrictRdfError {
+impl<'a> StrictRdfError<'a> {
+    fn new(term: GeneralizedTerm<'a>, message: &str) -> StrictRdfError<'a> {
+        StrictRdfError {
+            message: message.into(),
+            term,
+            position: None,
+        }
+    }
+
+    /// Records which [`GeneralizedQuad`]/[`GeneralizedTriple`] slot this error came from.
+    fn in_position(mut self, position: TermPosition) -> StrictRdfError<'a> {
+        self.position = Some(position);
+        self
+    }
+
+    /// Gives back the [`GeneralizedTerm`] that failed to convert to strict RDF.
+    pub fn into_term(self) -> GeneralizedTerm<'a> {
+        self.term
+    }
+}
+
+impl<'a> fmt::Display for StrictRdfError<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "StrictRdfError: ?{}", self.message)
+        write!(f, "StrictRdfError: ?{}", self.message)?;
+        if let Some(position) = self.position {
+            write!(f, " (at {:?})", position)?;
+        }
+        Ok(())
     }
 }
 
-impl Error for StrictRdfError {}
+impl<'a> Error for StrictRdfError<'a> {}
 
-impl From<&str> for StrictRdfError {
-    fn from(message: &str) -> StrictRdfError {
-        StrictRdfError {
-            message: message.into(),
+impl<'a> From<StrictRdfError<'a>> for GeneralizedTerm<'a> {
+    fn from(error: StrictRdfError<'a>) -> GeneralizedTerm<'a> {
+        error.into_term()
+    }
+}
+
+//
+
+/// An owned, lifetime-free counterpart to [`NamedNode`].
+#[derive(Eq, PartialEq, Ord, PartialOrd, Debug, Clone, Hash)]
+pub struct OwnedNamedNode {
+    pub iri: String,
+}
+
+impl OwnedNamedNode {
+    /// Borrows this node as a [`NamedNode`].
+    pub fn as_ref(&self) -> NamedNode<'_> {
+        NamedNode { iri: &self.iri }
+    }
+}
+
+impl<'a> From<NamedNode<'a>> for OwnedNamedNode {
+    fn from(other: NamedNode<'a>) -> OwnedNamedNode {
+        OwnedNamedNode {
+            iri: other.iri.to_owned(),
         }
     }
 }
+
+impl fmt::Display for OwnedNamedNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+/// An owned, lifetime-free counterpart to [`BlankNode`].
+#[derive(Eq, PartialEq, Ord, PartialOrd, Debug, Clone, Hash)]
+pub struct OwnedBlankNode {
+    pub id: String,
+}
+
+impl OwnedBlankNode {
+    /// Borrows this node as a [`BlankNode`].
+    pub fn as_ref(&self) -> BlankNode<'_> {
+        BlankNode { id: &self.id }
+    }
+}
+
+impl<'a> From<BlankNode<'a>> for OwnedBlankNode {
+    fn from(other: BlankNode<'a>) -> OwnedBlankNode {
+        OwnedBlankNode {
+            id: other.id.to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OwnedBlankNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+/// An owned, lifetime-free counterpart to [`Literal`].
+#[derive(Eq, PartialEq, Ord, PartialOrd, Debug, Clone, Hash)]
+pub enum OwnedLiteral {
+    Simple {
+        value: String,
+    },
+    LanguageTaggedString {
+        value: String,
+        language: String,
+    },
+    Typed {
+        value: String,
+        datatype: OwnedNamedNode,
+    },
+}
+
+impl OwnedLiteral {
+    /// Borrows this literal as a [`Literal`].
+    pub fn as_ref(&self) -> Literal<'_> {
+        match self {
+            OwnedLiteral::Simple { value } => Literal::Simple { value },
+            OwnedLiteral::LanguageTaggedString { value, language } => {
+                Literal::LanguageTaggedString { value, language }
+            }
+            OwnedLiteral::Typed { value, datatype } => Literal::Typed {
+                value,
+                datatype: datatype.as_ref(),
+            },
+        }
+    }
+}
+
+impl<'a> From<Literal<'a>> for OwnedLiteral {
+    fn from(other: Literal<'a>) -> OwnedLiteral {
+        match other {
+            Literal::Simple { value } => OwnedLiteral::Simple {
+                value: value.to_owned(),
+            },
+            Literal::LanguageTaggedString { value, language } => {
+                OwnedLiteral::LanguageTaggedString {
+                    value: value.to_owned(),
+                    language: language.to_owned(),
+                }
+            }
+            Literal::Typed { value, datatype } => OwnedLiteral::Typed {
+                value: value.to_owned(),
+                datatype: datatype.into(),
+            },
+        }
+    }
+}
+
+impl fmt::Display for OwnedLiteral {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+/// An owned, lifetime-free counterpart to [`Variable`].
+#[derive(Eq, PartialEq, Ord, PartialOrd, Debug, Clone, Hash)]
+pub struct OwnedVariable {
+    pub name: String,
+}
+
+impl OwnedVariable {
+    /// Borrows this variable as a [`Variable`].
+    pub fn as_ref(&self) -> Variable<'_> {
+        Variable { name: &self.name }
+    }
+}
+
+impl<'a> From<Variable<'a>> for OwnedVariable {
+    fn from(other: Variable<'a>) -> OwnedVariable {
+        OwnedVariable {
+            name: other.name.to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OwnedVariable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+//
+
+/// An owned, lifetime-free counterpart to [`GeneralizedTerm`], usable as a key in a `HashSet` or
+/// `BTreeMap` (or stored anywhere that must outlive the buffer a parser borrowed from).
+///
+/// Build one from a borrowed term with [`GeneralizedTerm::into_owned`]/`.into()`, and borrow it
+/// back out with [`OwnedGeneralizedTerm::as_ref`] — strict RDF conversions are only implemented
+/// on the borrowed [`GeneralizedTerm`], so going from an owned term to strict RDF is
+/// `owned.as_ref().try_into()`, same as any other borrowed term.
+///
+/// A quoted triple round-trips like any other term, without leaking: `as_ref` rebuilds a fresh
+/// borrowed [`GeneralizedTriple`] on every call, and the owned `Box` is dropped normally.
+///
+/// ```
+/// use rio_api::gmodel::{GeneralizedTerm, GeneralizedTriple, Variable};
+///
+/// let quoted = GeneralizedTerm::QuotedTriple(Box::new(GeneralizedTriple {
+///     subject: Variable { name: "s" }.into(),
+///     predicate: Variable { name: "p" }.into(),
+///     object: Variable { name: "o" }.into(),
+/// }));
+/// let owned = quoted.clone().into_owned();
+/// assert_eq!(quoted, owned.as_ref());
+/// assert_eq!("<<?s ?p ?o>>", owned.to_string());
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Debug, Clone, Hash)]
+pub enum OwnedGeneralizedTerm {
+    NamedNode(OwnedNamedNode),
+    BlankNode(OwnedBlankNode),
+    Literal(OwnedLiteral),
+    Variable(OwnedVariable),
+    QuotedTriple(Box<OwnedGeneralizedTriple>),
+}
+
+impl OwnedGeneralizedTerm {
+    /// Borrows this term as a [`GeneralizedTerm`].
+    ///
+    /// A quoted triple is rebuilt into a freshly owned [`GeneralizedTriple`] borrowing from
+    /// `self`, mirroring how [`GeneralizedTerm::QuotedTriple`] itself owns its nested triple —
+    /// nothing here is leaked, the `Box` is dropped like any other owned value.
+    pub fn as_ref(&self) -> GeneralizedTerm<'_> {
+        match self {
+            OwnedGeneralizedTerm::NamedNode(inner) => GeneralizedTerm::NamedNode(inner.as_ref()),
+            OwnedGeneralizedTerm::BlankNode(inner) => GeneralizedTerm::BlankNode(inner.as_ref()),
+            OwnedGeneralizedTerm::Literal(inner) => GeneralizedTerm::Literal(inner.as_ref()),
+            OwnedGeneralizedTerm::Variable(inner) => GeneralizedTerm::Variable(inner.as_ref()),
+            OwnedGeneralizedTerm::QuotedTriple(inner) => {
+                GeneralizedTerm::QuotedTriple(Box::new(OwnedGeneralizedTriple::as_ref(inner)))
+            }
+        }
+    }
+}
+
+impl<'a> From<GeneralizedTerm<'a>> for OwnedGeneralizedTerm {
+    fn from(other: GeneralizedTerm<'a>) -> OwnedGeneralizedTerm {
+        match other {
+            GeneralizedTerm::NamedNode(inner) => OwnedGeneralizedTerm::NamedNode(inner.into()),
+            GeneralizedTerm::BlankNode(inner) => OwnedGeneralizedTerm::BlankNode(inner.into()),
+            GeneralizedTerm::Literal(inner) => OwnedGeneralizedTerm::Literal(inner.into()),
+            GeneralizedTerm::Variable(inner) => OwnedGeneralizedTerm::Variable(inner.into()),
+            GeneralizedTerm::QuotedTriple(inner) => {
+                OwnedGeneralizedTerm::QuotedTriple(Box::new((*inner).into()))
+            }
+        }
+    }
+}
+
+impl<'a> GeneralizedTerm<'a> {
+    /// Copies this term into an [`OwnedGeneralizedTerm`] that does not borrow from `'a` anymore.
+    pub fn into_owned(self) -> OwnedGeneralizedTerm {
+        self.into()
+    }
+}
+
+impl fmt::Display for OwnedGeneralizedTerm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+/// An owned, lifetime-free counterpart to [`GeneralizedTriple`].
+#[derive(Eq, PartialEq, Ord, PartialOrd, Debug, Clone, Hash)]
+pub struct OwnedGeneralizedTriple {
+    pub subject: OwnedGeneralizedTerm,
+    pub predicate: OwnedGeneralizedTerm,
+    pub object: OwnedGeneralizedTerm,
+}
+
+impl OwnedGeneralizedTriple {
+    /// Borrows this triple as a [`GeneralizedTriple`].
+    pub fn as_ref(&self) -> GeneralizedTriple<'_> {
+        GeneralizedTriple {
+            subject: self.subject.as_ref(),
+            predicate: self.predicate.as_ref(),
+            object: self.object.as_ref(),
+        }
+    }
+}
+
+impl<'a> From<GeneralizedTriple<'a>> for OwnedGeneralizedTriple {
+    fn from(other: GeneralizedTriple<'a>) -> OwnedGeneralizedTriple {
+        OwnedGeneralizedTriple {
+            subject: other.subject.into(),
+            predicate: other.predicate.into(),
+            object: other.object.into(),
+        }
+    }
+}
+
+impl<'a> GeneralizedTriple<'a> {
+    /// Copies this triple into an [`OwnedGeneralizedTriple`] that does not borrow from `'a` anymore.
+    pub fn into_owned(self) -> OwnedGeneralizedTriple {
+        self.into()
+    }
+}
+
+impl fmt::Display for OwnedGeneralizedTriple {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+/// An owned, lifetime-free counterpart to [`GeneralizedQuad`].
+#[derive(Eq, PartialEq, Ord, PartialOrd, Debug, Clone, Hash)]
+pub struct OwnedGeneralizedQuad {
+    pub subject: OwnedGeneralizedTerm,
+    pub predicate: OwnedGeneralizedTerm,
+    pub object: OwnedGeneralizedTerm,
+    pub graph_name: Option<OwnedGeneralizedTerm>,
+}
+
+impl OwnedGeneralizedQuad {
+    /// Borrows this quad as a [`GeneralizedQuad`].
+    pub fn as_ref(&self) -> GeneralizedQuad<'_> {
+        GeneralizedQuad {
+            subject: self.subject.as_ref(),
+            predicate: self.predicate.as_ref(),
+            object: self.object.as_ref(),
+            graph_name: self.graph_name.as_ref().map(OwnedGeneralizedTerm::as_ref),
+        }
+    }
+}
+
+impl<'a> From<GeneralizedQuad<'a>> for OwnedGeneralizedQuad {
+    fn from(other: GeneralizedQuad<'a>) -> OwnedGeneralizedQuad {
+        OwnedGeneralizedQuad {
+            subject: other.subject.into(),
+            predicate: other.predicate.into(),
+            object: other.object.into(),
+            graph_name: other.graph_name.map(GeneralizedTerm::into_owned),
+        }
+    }
+}
+
+impl<'a> GeneralizedQuad<'a> {
+    /// Copies this quad into an [`OwnedGeneralizedQuad`] that does not borrow from `'a` anymore.
+    pub fn into_owned(self) -> OwnedGeneralizedQuad {
+        self.into()
+    }
+}
+
+impl fmt::Display for OwnedGeneralizedQuad {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}